@@ -0,0 +1,179 @@
+//! Diagnostics produced while lexing and parsing.
+//!
+//! The parser is designed to never give up on invalid input: errors are
+//! collected here instead of aborting the parse, so a single syntax
+//! mistake doesn't stop the rest of a file (or a whole project) from being
+//! linted.
+//!
+//! A diagnostic can also carry [`Suggestion`]s: concrete byte-range edits
+//! that fix the problem. Because the parser is lossless and every node
+//! already tracks precise spans, a suggestion can be applied directly to
+//! the original source to reconstruct corrected text - see
+//! [`apply_machine_applicable`].
+
+use crate::macros::EqIgnoreSpan;
+use crate::span::Span;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Diagnostic {
+    pub span: Span,
+    pub message: String,
+    pub suggestions: Vec<Suggestion>,
+}
+
+impl Diagnostic {
+    pub fn new(span: Span, message: impl Into<String>) -> Self {
+        Self {
+            span,
+            message: message.into(),
+            suggestions: Vec::new(),
+        }
+    }
+
+    pub fn with_suggestion(mut self, suggestion: Suggestion) -> Self {
+        self.suggestions.push(suggestion);
+        self
+    }
+}
+
+impl EqIgnoreSpan for Diagnostic {
+    fn eq_ignore_span(&self, other: &Self) -> bool {
+        self.message == other.message && self.suggestions == other.suggestions
+    }
+}
+
+/// How safe a [`Suggestion`] is to apply without a human looking at it.
+/// Mirrors rustc's `Applicability`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Applicability {
+    /// The suggested edit is known to be correct and can be applied
+    /// automatically, e.g. by a `--fix` mode.
+    MachineApplicable,
+    /// The suggested edit is probably what's wanted, but might change the
+    /// meaning of the code; a human should confirm it.
+    MaybeIncorrect,
+    /// The suggested edit contains placeholders (e.g. `/* value */`) that
+    /// need to be filled in before it makes sense.
+    HasPlaceholders,
+    /// The suggestion's applicability hasn't been categorized.
+    Unspecified,
+}
+
+/// A suggested fix for a [`Diagnostic`]: a human-readable `message` plus
+/// one or more `(Span, replacement)` edits to apply together.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Suggestion {
+    pub message: String,
+    pub substitutions: Vec<(Span, String)>,
+    pub applicability: Applicability,
+}
+
+impl Suggestion {
+    pub fn new(message: impl Into<String>, applicability: Applicability, substitutions: Vec<(Span, String)>) -> Self {
+        Self {
+            message: message.into(),
+            substitutions,
+            applicability,
+        }
+    }
+}
+
+/// Two [`Suggestion`] edits whose spans overlap, so applying both at once
+/// would be ambiguous.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OverlappingSuggestion {
+    pub first: Span,
+    pub second: Span,
+}
+
+/// Collects every [`Applicability::MachineApplicable`] edit across
+/// `diagnostics` and applies them to `source`, returning the corrected
+/// text. Rejects the whole batch if any two machine-applicable edits
+/// overlap, since applying both could silently corrupt the source.
+pub fn apply_machine_applicable(source: &str, diagnostics: &[Diagnostic]) -> Result<String, OverlappingSuggestion> {
+    let mut edits: Vec<(Span, &str)> = diagnostics
+        .iter()
+        .flat_map(|diagnostic| diagnostic.suggestions.iter())
+        .filter(|suggestion| suggestion.applicability == Applicability::MachineApplicable)
+        .flat_map(|suggestion| {
+            suggestion
+                .substitutions
+                .iter()
+                .map(|(span, replacement)| (*span, replacement.as_str()))
+        })
+        .collect();
+    edits.sort_by_key(|(span, _)| span.start);
+
+    for pair in edits.windows(2) {
+        let (first, second) = (pair[0].0, pair[1].0);
+        if first.end > second.start {
+            return Err(OverlappingSuggestion { first, second });
+        }
+    }
+
+    let mut out = String::with_capacity(source.len());
+    let mut cursor = 0;
+    for (span, replacement) in edits {
+        out.push_str(&source[cursor..span.start]);
+        out.push_str(replacement);
+        cursor = span.end;
+    }
+    out.push_str(&source[cursor..]);
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diagnostic_with_suggestion(span: Span, applicability: Applicability, substitutions: Vec<(Span, String)>) -> Diagnostic {
+        Diagnostic::new(span, "test diagnostic")
+            .with_suggestion(Suggestion::new("test suggestion", applicability, substitutions))
+    }
+
+    #[test]
+    fn applies_machine_applicable_suggestions() {
+        let source = "var x = 1";
+        let diagnostics = vec![diagnostic_with_suggestion(
+            Span::new(4, 5),
+            Applicability::MachineApplicable,
+            vec![(Span::new(4, 5), "y".to_string())],
+        )];
+        assert_eq!(apply_machine_applicable(source, &diagnostics).unwrap(), "var y = 1");
+    }
+
+    #[test]
+    fn skips_suggestions_that_are_not_machine_applicable() {
+        let source = "var x = 1";
+        let diagnostics = vec![diagnostic_with_suggestion(
+            Span::new(4, 5),
+            Applicability::MaybeIncorrect,
+            vec![(Span::new(4, 5), "y".to_string())],
+        )];
+        assert_eq!(apply_machine_applicable(source, &diagnostics).unwrap(), source);
+    }
+
+    #[test]
+    fn rejects_overlapping_machine_applicable_suggestions() {
+        let source = "var x = 1";
+        let diagnostics = vec![
+            diagnostic_with_suggestion(
+                Span::new(4, 5),
+                Applicability::MachineApplicable,
+                vec![(Span::new(4, 6), "yz".to_string())],
+            ),
+            diagnostic_with_suggestion(
+                Span::new(5, 6),
+                Applicability::MachineApplicable,
+                vec![(Span::new(5, 9), "= 2".to_string())],
+            ),
+        ];
+        assert_eq!(
+            apply_machine_applicable(source, &diagnostics).unwrap_err(),
+            OverlappingSuggestion {
+                first: Span::new(4, 6),
+                second: Span::new(5, 9),
+            }
+        );
+    }
+}
@@ -10,4 +10,5 @@ pub mod parser;
 pub mod serialize;
 pub mod span;
 pub mod unicode;
-pub mod util;
\ No newline at end of file
+pub mod util;
+pub mod visit;
\ No newline at end of file
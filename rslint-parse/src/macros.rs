@@ -0,0 +1,141 @@
+//! Declarative codegen helpers shared across the crate.
+//!
+//! This is where the boilerplate that would otherwise have to be hand
+//! written once per CST node lives: the plumbing [`crate::visit`] needs to
+//! recurse into `Option<T>`/`Vec<T>`/`Box<T>` fields, and the
+//! [`assert_eq_ignore_span`] helper tests use to compare trees while
+//! disregarding byte offsets.
+
+/// Implemented for every CST node so two trees can be compared while
+/// treating all [`Span`](crate::span::Span) (and [`LiteralWhitespace`])
+/// fields as always-equal. Mirrors `PartialEq`, but spans never cause a
+/// mismatch.
+///
+/// Leaf wrappers (`Option`, `Vec`, `Box`) get a blanket impl below so node
+/// impls only need to delegate field-by-field.
+pub trait EqIgnoreSpan {
+    fn eq_ignore_span(&self, other: &Self) -> bool;
+}
+
+impl<T: EqIgnoreSpan> EqIgnoreSpan for Box<T> {
+    fn eq_ignore_span(&self, other: &Self) -> bool {
+        (**self).eq_ignore_span(&**other)
+    }
+}
+
+impl<T: EqIgnoreSpan> EqIgnoreSpan for Option<T> {
+    fn eq_ignore_span(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Some(a), Some(b)) => a.eq_ignore_span(b),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+impl<T: EqIgnoreSpan> EqIgnoreSpan for Vec<T> {
+    fn eq_ignore_span(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().zip(other.iter()).all(|(a, b)| a.eq_ignore_span(b))
+    }
+}
+
+/// Implements [`EqIgnoreSpan`] for a struct by naming the fields to compare
+/// and the fields to always treat as equal (typically `span` and any
+/// `LiteralWhitespace`/`Semicolon` fields).
+///
+/// ```ignore
+/// impl_eq_ignore_span! {
+///     struct IfStmt {
+///         compare: [condition, cons, alt],
+///         ignore: [span, if_whitespace, open_paren_whitespace, close_paren_whitespace, else_whitespace],
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! impl_eq_ignore_span {
+    ($(struct $ty:ident { compare: [$($field:ident),* $(,)?], ignore: [$($ignored:ident),* $(,)?] $(,)? })*) => {
+        $(
+            impl $crate::macros::EqIgnoreSpan for $ty {
+                fn eq_ignore_span(&self, other: &Self) -> bool {
+                    $(let _ = (&self.$ignored, &other.$ignored);)*
+                    true $(&& self.$field.eq_ignore_span(&other.$field))*
+                }
+            }
+        )*
+    };
+}
+
+/// Generates the `Stmt`-variant half of [`crate::visit::Visit`] and
+/// [`crate::visit::VisitMut`]: a default trait method per variant plus the
+/// `walk_stmt`/`walk_mut_stmt` dispatcher, all from one table. This is not
+/// a field-reading derive - `macro_rules!` can't see a struct's fields, so
+/// it can't generate a `walk_if_stmt` that knows to recurse into `IfStmt`'s
+/// `condition`/`cons`/`alt`. What it removes is the *other* boilerplate:
+/// adding a new `Stmt` variant means adding one line to `variants { ... }`
+/// here instead of hand-editing the trait and both dispatch matches
+/// separately; the `walk_*`/`walk_mut_*` function the table points at is
+/// still hand-written once per node type, and stays that way.
+///
+/// Anything that isn't a direct `Stmt` variant - the `visit_stmt` entry
+/// point itself, and the `Expr`/`LiteralExpr`/`Declaration`/`ForStmtInit`
+/// leaf hooks - doesn't fit this table's one-row-per-variant shape, so it's
+/// spliced into the trait bodies verbatim via `extra`/`extra_mut`.
+#[macro_export]
+macro_rules! define_stmt_visit {
+    (
+        extra { $($extra:item)* }
+        extra_mut { $($extra_mut:item)* }
+        variants {
+            $($variant:ident($inner:ty) => $visit_fn:ident, $walk_fn:ident, $visit_mut_fn:ident, $walk_mut_fn:ident;)*
+        }
+    ) => {
+        pub trait Visit {
+            $($extra)*
+            $(
+                fn $visit_fn(&mut self, node: &$inner) {
+                    $walk_fn(self, node);
+                }
+            )*
+        }
+
+        pub fn walk_stmt<V: Visit + ?Sized>(v: &mut V, node: &$crate::parser::cst::stmt::Stmt) {
+            use $crate::parser::cst::stmt::Stmt::*;
+            match node {
+                $($variant(n) => v.$visit_fn(n),)*
+            }
+        }
+
+        pub trait VisitMut {
+            $($extra_mut)*
+            $(
+                fn $visit_mut_fn(&mut self, node: &mut $inner) {
+                    $walk_mut_fn(self, node);
+                }
+            )*
+        }
+
+        pub fn walk_mut_stmt<V: VisitMut + ?Sized>(v: &mut V, node: &mut $crate::parser::cst::stmt::Stmt) {
+            use $crate::parser::cst::stmt::Stmt::*;
+            match node {
+                $($variant(n) => v.$visit_mut_fn(n),)*
+            }
+        }
+    };
+}
+
+/// Asserts two CST values are equal while ignoring every `Span` (and
+/// `LiteralWhitespace`) field, panicking with the usual `Debug` diff on
+/// failure. Lets parser tests assert tree shape independent of byte offsets.
+#[macro_export]
+macro_rules! assert_eq_ignore_span {
+    ($left:expr, $right:expr $(,)?) => {{
+        let left = &$left;
+        let right = &$right;
+        if !$crate::macros::EqIgnoreSpan::eq_ignore_span(left, right) {
+            panic!(
+                "assertion failed: `(left == right)` (ignoring spans)\n  left: `{:?}`\n right: `{:?}`",
+                left, right
+            );
+        }
+    }};
+}
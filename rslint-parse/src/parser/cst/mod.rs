@@ -0,0 +1,4 @@
+pub mod declaration;
+pub mod expr;
+pub mod module;
+pub mod stmt;
@@ -0,0 +1,171 @@
+use super::expr::*;
+use super::declaration::Declaration;
+use super::stmt::{Semicolon, StmtListItem};
+use crate::impl_eq_ignore_span;
+use crate::macros::EqIgnoreSpan;
+use crate::span::Span;
+
+/// A single binding inside an [`ImportDecl`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ImportSpecifier {
+    /// `import foo from "mod"`
+    Default(LiteralExpr),
+    /// `import * as foo from "mod"`
+    Namespace {
+        star_whitespace: LiteralWhitespace,
+        as_whitespace: LiteralWhitespace,
+        local: LiteralExpr,
+    },
+    /// One entry of a `{ a, b as c }` list.
+    Named {
+        span: Span,
+        imported: LiteralExpr,
+        as_whitespace: Option<LiteralWhitespace>,
+        local: Option<LiteralExpr>,
+    },
+}
+
+/// `import <specifiers> from "source";`. Only valid at the top level of a
+/// module (see [`crate::parser::ParseGoal`] and
+/// [`crate::parser::check_module_item_allowed`]); a `Script`-mode parse
+/// rejects the leading `import` instead of accepting this node.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ImportDecl {
+    pub span: Span,
+    pub import_whitespace: LiteralWhitespace,
+    pub specifiers: Vec<ImportSpecifier>,
+    pub comma_whitespaces: Vec<LiteralWhitespace>,
+    pub open_brace_whitespace: Option<LiteralWhitespace>,
+    pub close_brace_whitespace: Option<LiteralWhitespace>,
+    pub from_whitespace: LiteralWhitespace,
+    pub source: LiteralExpr,
+    pub semi: Semicolon,
+}
+
+/// One entry of an `export { a, b as c }` list.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ExportSpecifier {
+    pub span: Span,
+    pub local: LiteralExpr,
+    pub as_whitespace: Option<LiteralWhitespace>,
+    pub exported: Option<LiteralExpr>,
+}
+
+/// `export ...;` in any of its forms. Like [`ImportDecl`], only valid at
+/// the top level of a module - see
+/// [`crate::parser::check_module_item_allowed`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ExportDecl {
+    /// `export { a, b as c }`, optionally re-exported `from "mod"`.
+    Named {
+        span: Span,
+        export_whitespace: LiteralWhitespace,
+        open_brace_whitespace: LiteralWhitespace,
+        specifiers: Vec<ExportSpecifier>,
+        comma_whitespaces: Vec<LiteralWhitespace>,
+        close_brace_whitespace: LiteralWhitespace,
+        from_whitespace: Option<LiteralWhitespace>,
+        source: Option<LiteralExpr>,
+        semi: Semicolon,
+    },
+    /// `export default <expr>;`
+    Default {
+        span: Span,
+        export_whitespace: LiteralWhitespace,
+        default_whitespace: LiteralWhitespace,
+        value: Expr,
+        semi: Semicolon,
+    },
+    /// `export <declaration>`, e.g. `export function f() {}`.
+    Declaration {
+        span: Span,
+        export_whitespace: LiteralWhitespace,
+        declaration: Declaration,
+    },
+    /// `export * from "mod";`
+    All {
+        span: Span,
+        export_whitespace: LiteralWhitespace,
+        star_whitespace: LiteralWhitespace,
+        from_whitespace: LiteralWhitespace,
+        source: LiteralExpr,
+        semi: Semicolon,
+    },
+}
+
+impl ExportDecl {
+    pub fn span(&self) -> Span {
+        use ExportDecl::*;
+        match self {
+            Named { span, .. } => *span,
+            Default { span, .. } => *span,
+            Declaration { span, .. } => *span,
+            All { span, .. } => *span,
+        }
+    }
+}
+
+/// A top-level item in a module: either anything a script could contain,
+/// or one of the module-only `import`/`export` declarations.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ModuleItem {
+    StmtListItem(StmtListItem),
+    Import(ImportDecl),
+    Export(ExportDecl),
+}
+
+impl EqIgnoreSpan for ImportSpecifier {
+    fn eq_ignore_span(&self, other: &Self) -> bool {
+        use ImportSpecifier::*;
+        match (self, other) {
+            (Default(a), Default(b)) => a.eq_ignore_span(b),
+            (Namespace { local: a, .. }, Namespace { local: b, .. }) => a.eq_ignore_span(b),
+            (Named { imported: a, local: a_local, .. }, Named { imported: b, local: b_local, .. }) => {
+                a.eq_ignore_span(b) && a_local.eq_ignore_span(b_local)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl_eq_ignore_span! {
+    struct ExportSpecifier {
+        compare: [local, exported],
+        ignore: [span, as_whitespace],
+    }
+}
+
+impl_eq_ignore_span! {
+    struct ImportDecl {
+        compare: [specifiers, source],
+        ignore: [span, import_whitespace, comma_whitespaces, open_brace_whitespace, close_brace_whitespace, from_whitespace, semi],
+    }
+}
+
+impl EqIgnoreSpan for ExportDecl {
+    fn eq_ignore_span(&self, other: &Self) -> bool {
+        use ExportDecl::*;
+        match (self, other) {
+            (
+                Named { specifiers: a, source: a_source, .. },
+                Named { specifiers: b, source: b_source, .. },
+            ) => a.eq_ignore_span(b) && a_source.eq_ignore_span(b_source),
+            (Default { value: a, .. }, Default { value: b, .. }) => a.eq_ignore_span(b),
+            (Declaration { declaration: a, .. }, Declaration { declaration: b, .. }) => a.eq_ignore_span(b),
+            (All { source: a, .. }, All { source: b, .. }) => a.eq_ignore_span(b),
+            _ => false,
+        }
+    }
+}
+
+impl EqIgnoreSpan for ModuleItem {
+    fn eq_ignore_span(&self, other: &Self) -> bool {
+        use ModuleItem::*;
+        match (self, other) {
+            (StmtListItem(a), StmtListItem(b)) => a.eq_ignore_span(b),
+            (Import(a), Import(b)) => a.eq_ignore_span(b),
+            (Export(a), Export(b)) => a.eq_ignore_span(b),
+            _ => false,
+        }
+    }
+}
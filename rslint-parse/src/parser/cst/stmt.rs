@@ -1,5 +1,8 @@
 use super::expr::*;
 use super::declaration::Declaration;
+use crate::diagnostic::Diagnostic;
+use crate::impl_eq_ignore_span;
+use crate::macros::EqIgnoreSpan;
 use crate::span::Span;
 
 #[derive(Clone, PartialEq, Debug, Eq, Hash)]
@@ -20,7 +23,12 @@ pub enum Stmt {
     Try(TryStmt),
     For(ForStmt),
     ForIn(ForInStmt),
+    ForOf(ForOfStmt),
     With(WithStmt),
+    /// A malformed statement recovered from a syntax error. The span
+    /// covers everything skipped during recovery, so the tree stays
+    /// lossless even though the content couldn't be parsed.
+    Error(ErrorStmt),
 }
 
 impl Stmt {
@@ -43,7 +51,9 @@ impl Stmt {
             Try(data) => data.span,
             For(data) => data.span,
             ForIn(data) => data.span,
+            ForOf(data) => data.span,
             With(data) => data.span,
+            Error(data) => data.span,
         }
     }
 }
@@ -278,6 +288,33 @@ pub struct ForInStmt {
     pub body: Box<Stmt>,
 }
 
+/// A statement the parser couldn't make sense of. Produced by
+/// [`crate::parser::recovery::recover_stmt`]; `diagnostics` is always
+/// non-empty.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ErrorStmt {
+    pub span: Span,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+/// `for (left of right) body`. `of` is a contextual keyword rather than a
+/// reserved word, so the parser only commits to this production after
+/// seeing `of` where an identifier (e.g. an update expression) could
+/// otherwise start. That lookahead lives in the statement parser that
+/// builds this node, not here - by the time a `ForOfStmt` exists the
+/// `for`/`ForIn`/`ForOf` choice has already been made.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ForOfStmt {
+    pub span: Span,
+    pub for_whitespace: LiteralWhitespace,
+    pub open_paren_whitespace: LiteralWhitespace,
+    pub close_paren_whitespace: LiteralWhitespace,
+    pub left: ForStmtInit,
+    pub right: Expr,
+    pub of_whitespace: LiteralWhitespace,
+    pub body: Box<Stmt>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct WithStmt {
     pub span: Span,
@@ -286,4 +323,160 @@ pub struct WithStmt {
     pub close_paren_whitespace: LiteralWhitespace,
     pub object: Expr,
     pub body: Box<Stmt>,
+}
+
+// `Expr`, `LiteralExpr` and `Declaration` don't carry the span-ignoring
+// machinery themselves yet, so bridge them through plain `PartialEq` for
+// now; they can grow a real `EqIgnoreSpan` impl in `expr.rs`/`declaration.rs`
+// without anything here needing to change.
+macro_rules! eq_ignore_span_via_partial_eq {
+    ($($ty:ty),* $(,)?) => {
+        $(impl EqIgnoreSpan for $ty {
+            fn eq_ignore_span(&self, other: &Self) -> bool {
+                self == other
+            }
+        })*
+    };
+}
+eq_ignore_span_via_partial_eq!(Expr, LiteralExpr, Declaration);
+
+impl_eq_ignore_span! {
+    struct Declarator {
+        compare: [name, value],
+        ignore: [span, initializer_whitespace],
+    }
+    struct VarStmt {
+        compare: [declared],
+        ignore: [span, comma_whitespaces, var_whitespace, semi],
+    }
+    struct BlockStmt {
+        compare: [stmts],
+        ignore: [span, open_brace_whitespace, close_brace_whitespace],
+    }
+    struct EmptyStmt {
+        compare: [],
+        ignore: [span, semi_whitespace],
+    }
+    struct ExprStmt {
+        compare: [expr],
+        ignore: [span, semi],
+    }
+    struct IfStmt {
+        compare: [condition, cons, alt],
+        ignore: [span, if_whitespace, open_paren_whitespace, close_paren_whitespace, else_whitespace],
+    }
+    struct Case {
+        compare: [default, test, cons],
+        ignore: [span, whitespace, colon_whitespace],
+    }
+    struct SwitchStmt {
+        compare: [test, cases],
+        ignore: [span, switch_whitespace, open_paren_whitespace, close_paren_whitespace, open_brace_whitespace, close_brace_whitespace],
+    }
+    struct ThrowStmt {
+        compare: [arg],
+        ignore: [span, semi, throw_whitespace],
+    }
+    struct WhileStmt {
+        compare: [condition, cons],
+        ignore: [span, while_whitespace, open_paren_whitespace, close_paren_whitespace],
+    }
+    struct DoWhileStmt {
+        compare: [condition, cons],
+        ignore: [span, do_whitespace, while_whitespace, open_paren_whitespace, close_paren_whitespace],
+    }
+    struct LabelledStmt {
+        compare: [label, body],
+        ignore: [span, colon_whitespace],
+    }
+    struct BreakStmt {
+        compare: [label],
+        ignore: [span, break_whitespace, semi],
+    }
+    struct ContinueStmt {
+        compare: [label],
+        ignore: [span, continue_whitespace, semi],
+    }
+    struct ReturnStmt {
+        compare: [value],
+        ignore: [span, return_whitespace, semi],
+    }
+    struct CatchClause {
+        compare: [param, body],
+        ignore: [span, catch_whitespace, open_paren_whitespace, close_paren_whitespace],
+    }
+    struct TryStmt {
+        compare: [test, handler, finalizer],
+        ignore: [span, try_whitespace, final_whitespace],
+    }
+    struct ForStmt {
+        compare: [init, test, update, body],
+        ignore: [span, for_whitespace, open_paren_whitespace, close_paren_whitespace, init_semicolon_whitespace, test_semicolon_whitespace],
+    }
+    struct ForInStmt {
+        compare: [left, right, body],
+        ignore: [span, for_whitespace, open_paren_whitespace, close_paren_whitespace, in_whitespace],
+    }
+    struct ForOfStmt {
+        compare: [left, right, body],
+        ignore: [span, for_whitespace, open_paren_whitespace, close_paren_whitespace, of_whitespace],
+    }
+    struct WithStmt {
+        compare: [object, body],
+        ignore: [span, with_whitespace, open_paren_whitespace, close_paren_whitespace],
+    }
+    struct ErrorStmt {
+        compare: [diagnostics],
+        ignore: [span],
+    }
+}
+
+impl EqIgnoreSpan for ForStmtInit {
+    fn eq_ignore_span(&self, other: &Self) -> bool {
+        use ForStmtInit::*;
+        match (self, other) {
+            (Expr(a), Expr(b)) => a.eq_ignore_span(b),
+            (Var(a), Var(b)) => a.eq_ignore_span(b),
+            _ => false,
+        }
+    }
+}
+
+impl EqIgnoreSpan for Stmt {
+    fn eq_ignore_span(&self, other: &Self) -> bool {
+        use Stmt::*;
+        match (self, other) {
+            (Variable(a), Variable(b)) => a.eq_ignore_span(b),
+            (Empty(a), Empty(b)) => a.eq_ignore_span(b),
+            (Block(a), Block(b)) => a.eq_ignore_span(b),
+            (Expr(a), Expr(b)) => a.eq_ignore_span(b),
+            (If(a), If(b)) => a.eq_ignore_span(b),
+            (Switch(a), Switch(b)) => a.eq_ignore_span(b),
+            (Throw(a), Throw(b)) => a.eq_ignore_span(b),
+            (While(a), While(b)) => a.eq_ignore_span(b),
+            (DoWhile(a), DoWhile(b)) => a.eq_ignore_span(b),
+            (Labelled(a), Labelled(b)) => a.eq_ignore_span(b),
+            (Break(a), Break(b)) => a.eq_ignore_span(b),
+            (Continue(a), Continue(b)) => a.eq_ignore_span(b),
+            (Return(a), Return(b)) => a.eq_ignore_span(b),
+            (Try(a), Try(b)) => a.eq_ignore_span(b),
+            (For(a), For(b)) => a.eq_ignore_span(b),
+            (ForIn(a), ForIn(b)) => a.eq_ignore_span(b),
+            (ForOf(a), ForOf(b)) => a.eq_ignore_span(b),
+            (With(a), With(b)) => a.eq_ignore_span(b),
+            (Error(a), Error(b)) => a.eq_ignore_span(b),
+            _ => false,
+        }
+    }
+}
+
+impl EqIgnoreSpan for StmtListItem {
+    fn eq_ignore_span(&self, other: &Self) -> bool {
+        use StmtListItem::*;
+        match (self, other) {
+            (Declaration(a), Declaration(b)) => a.eq_ignore_span(b),
+            (Stmt(a), Stmt(b)) => a.eq_ignore_span(b),
+            _ => false,
+        }
+    }
 }
\ No newline at end of file
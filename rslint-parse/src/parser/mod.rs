@@ -0,0 +1,73 @@
+pub mod cst;
+pub mod recovery;
+
+use crate::diagnostic::Diagnostic;
+use crate::parser::cst::module::ModuleItem;
+use crate::span::Span;
+
+/// Which top-level grammar the parser accepts, mirroring the spec's
+/// Script/Module goal symbols. `import`/`export` declarations
+/// ([`cst::module::ImportDecl`], [`cst::module::ExportDecl`]) are only
+/// valid in `Module` mode; see [`check_module_item_allowed`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseGoal {
+    Script,
+    Module,
+}
+
+/// Rejects a [`ModuleItem`] that isn't valid under `goal`. The top-level
+/// item parser calls this right after recognizing an `import`/`export`
+/// declaration, before accepting it into the body: in `Script` mode
+/// neither keyword is part of the grammar, so it's reported as an error
+/// at the declaration's own span instead of being parsed silently into
+/// the tree.
+pub fn check_module_item_allowed(goal: ParseGoal, item: &ModuleItem) -> Result<(), Diagnostic> {
+    if goal == ParseGoal::Module {
+        return Ok(());
+    }
+    let (span, keyword): (Span, &str) = match item {
+        ModuleItem::StmtListItem(_) => return Ok(()),
+        ModuleItem::Import(decl) => (decl.span, "import"),
+        ModuleItem::Export(decl) => (decl.span(), "export"),
+    };
+    Err(Diagnostic::new(span, format!("`{keyword}` declarations are only valid in a module")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::cst::expr::LiteralWhitespace;
+    use crate::parser::cst::module::ExportDecl;
+    use crate::parser::cst::stmt::Semicolon;
+
+    // `ExportDecl::Named` is the only variant whose fields are all either a
+    // `Span`/`LiteralWhitespace` (known shape) or optional/empty - every
+    // other variant needs a real `Expr`/`LiteralExpr`/`Declaration`, which
+    // aren't part of this snapshot, so those can't be built as fixtures here.
+    fn bare_export(span: Span) -> ModuleItem {
+        ModuleItem::Export(ExportDecl::Named {
+            span,
+            export_whitespace: LiteralWhitespace { before: span, after: span },
+            open_brace_whitespace: LiteralWhitespace { before: span, after: span },
+            specifiers: Vec::new(),
+            comma_whitespaces: Vec::new(),
+            close_brace_whitespace: LiteralWhitespace { before: span, after: span },
+            from_whitespace: None,
+            source: None,
+            semi: Semicolon::Implicit,
+        })
+    }
+
+    #[test]
+    fn script_goal_rejects_export() {
+        let span = Span::new(0, 9);
+        let err = check_module_item_allowed(ParseGoal::Script, &bare_export(span)).unwrap_err();
+        assert_eq!(err.span, span);
+    }
+
+    #[test]
+    fn module_goal_allows_export() {
+        let span = Span::new(0, 9);
+        assert!(check_module_item_allowed(ParseGoal::Module, &bare_export(span)).is_ok());
+    }
+}
@@ -0,0 +1,92 @@
+//! Statement-level error recovery.
+//!
+//! Modeled on rustc's `SemiColonMode`/`BlockMode`: when the parser hits a
+//! token it can't make sense of partway through a statement, it doesn't
+//! abort the whole parse. It emits a [`Diagnostic`], skips forward to a
+//! predictable recovery point, and folds everything it skipped into a
+//! [`Stmt::Error`] so the caller can resume parsing normally from there.
+//! The produced span always covers exactly what was skipped, so the tree
+//! stays lossless even across a syntax error.
+
+use crate::diagnostic::Diagnostic;
+use crate::lexer::{Lexer, TokenKind};
+use crate::parser::cst::stmt::{ErrorStmt, Stmt};
+use crate::span::Span;
+
+/// Where a botched statement should resume parsing from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecoveryPoint {
+    /// Skip to the next `;`, or the next ASI opportunity.
+    Semicolon,
+    /// Skip to the next token that can start a statement (`if`, `for`,
+    /// `var`, `{`, …).
+    StmtStart,
+    /// Skip to the `}` that closes the enclosing `BlockStmt`.
+    MatchingBrace,
+}
+
+impl RecoveryPoint {
+    fn is_boundary(self, kind: &TokenKind) -> bool {
+        match self {
+            RecoveryPoint::Semicolon => matches!(kind, TokenKind::Semicolon | TokenKind::CloseBrace | TokenKind::Eof),
+            RecoveryPoint::StmtStart => *kind == TokenKind::Eof || kind.starts_stmt(),
+            RecoveryPoint::MatchingBrace => matches!(kind, TokenKind::CloseBrace | TokenKind::Eof),
+        }
+    }
+}
+
+/// Skips tokens from `lexer` until `point`'s boundary is reached, then
+/// returns a [`Stmt::Error`] covering `cause`'s span plus everything
+/// skipped. An explicit `;` at a [`RecoveryPoint::Semicolon`] boundary is
+/// consumed and included; every other boundary token is left for the
+/// caller to parse normally.
+pub fn recover_stmt(lexer: &mut Lexer, point: RecoveryPoint, cause: Diagnostic) -> Stmt {
+    let start = cause.span.start;
+    let mut end = cause.span.end;
+    while let Some(token) = lexer.current() {
+        if point.is_boundary(&token.kind) {
+            if point == RecoveryPoint::Semicolon && token.kind == TokenKind::Semicolon {
+                end = token.span.end;
+                lexer.bump();
+            } else {
+                end = token.span.start;
+            }
+            break;
+        }
+        end = token.span.end;
+        lexer.bump();
+    }
+    Stmt::Error(ErrorStmt {
+        span: Span::new(start, end),
+        diagnostics: vec![cause],
+    })
+}
+
+// `recover_stmt` itself needs a real `Lexer` to drive, which (along with
+// `TokenKind`'s full variant set) isn't part of this slice, so it can't be
+// exercised end-to-end here. What *is* tree-shaped and safe to pin down is
+// `RecoveryPoint::is_boundary`, the predicate each `RecoveryPoint` variant
+// drives its skip loop with - cover that per-variant instead.
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn semicolon_point_stops_at_semicolon_close_brace_or_eof() {
+        assert!(RecoveryPoint::Semicolon.is_boundary(&TokenKind::Semicolon));
+        assert!(RecoveryPoint::Semicolon.is_boundary(&TokenKind::CloseBrace));
+        assert!(RecoveryPoint::Semicolon.is_boundary(&TokenKind::Eof));
+    }
+
+    #[test]
+    fn stmt_start_point_stops_at_eof() {
+        assert!(RecoveryPoint::StmtStart.is_boundary(&TokenKind::Eof));
+    }
+
+    #[test]
+    fn matching_brace_point_stops_at_close_brace_or_eof() {
+        assert!(RecoveryPoint::MatchingBrace.is_boundary(&TokenKind::CloseBrace));
+        assert!(RecoveryPoint::MatchingBrace.is_boundary(&TokenKind::Eof));
+        assert!(!RecoveryPoint::MatchingBrace.is_boundary(&TokenKind::Semicolon));
+    }
+}
@@ -0,0 +1,540 @@
+//! Lossless source reconstruction.
+//!
+//! The CST is "(mostly) lossless": every `LiteralWhitespace` field stores
+//! only the `before`/`after` spans of the trivia surrounding a token, not
+//! a copy of the text. Printing a node means slicing those spans back out
+//! of the original `source` around each token (fixed keywords and
+//! punctuation are emitted literally, since their spelling never varies)
+//! and recursing into child nodes the same way. Doing this for an entire
+//! tree round-trips byte-for-byte back to the input - see the test below
+//! and [`crate::macros::assert_eq_ignore_span`] for the complementary
+//! "same shape" comparison used once whitespace isn't in play.
+
+use crate::parser::cst::expr::*;
+use crate::parser::cst::module::*;
+use crate::parser::cst::stmt::*;
+
+pub trait Print {
+    /// Appends this node's source representation to `out`, slicing
+    /// whitespace out of `source` by span.
+    fn print(&self, source: &str, out: &mut String);
+}
+
+impl<T: Print> Print for Vec<T> {
+    fn print(&self, source: &str, out: &mut String) {
+        for item in self {
+            item.print(source, out);
+        }
+    }
+}
+
+impl<T: Print> Print for Box<T> {
+    fn print(&self, source: &str, out: &mut String) {
+        (**self).print(source, out);
+    }
+}
+
+impl<T: Print> Print for Option<T> {
+    fn print(&self, source: &str, out: &mut String) {
+        if let Some(inner) = self {
+            inner.print(source, out);
+        }
+    }
+}
+
+/// Emits `before` whitespace, then `literal` (a keyword or punctuation,
+/// whose spelling is fixed by the grammar so it doesn't need to come from
+/// `source`), then `after` whitespace.
+fn token(source: &str, whitespace: &LiteralWhitespace, literal: &str, out: &mut String) {
+    out.push_str(&source[whitespace.before.start..whitespace.before.end]);
+    out.push_str(literal);
+    out.push_str(&source[whitespace.after.start..whitespace.after.end]);
+}
+
+impl Print for Semicolon {
+    fn print(&self, source: &str, out: &mut String) {
+        if let Semicolon::Explicit(whitespace) = self {
+            token(source, whitespace, ";", out);
+        }
+    }
+}
+
+impl Print for Declarator {
+    fn print(&self, source: &str, out: &mut String) {
+        self.name.print(source, out);
+        if let (Some(whitespace), Some(value)) = (&self.initializer_whitespace, &self.value) {
+            token(source, whitespace, "=", out);
+            value.print(source, out);
+        }
+    }
+}
+
+impl Print for VarStmt {
+    fn print(&self, source: &str, out: &mut String) {
+        token(source, &self.var_whitespace, "var", out);
+        for (i, declarator) in self.declared.iter().enumerate() {
+            if i > 0 {
+                token(source, &self.comma_whitespaces[i - 1], ",", out);
+            }
+            declarator.print(source, out);
+        }
+        self.semi.print(source, out);
+    }
+}
+
+impl Print for BlockStmt {
+    fn print(&self, source: &str, out: &mut String) {
+        token(source, &self.open_brace_whitespace, "{", out);
+        self.stmts.print(source, out);
+        token(source, &self.close_brace_whitespace, "}", out);
+    }
+}
+
+impl Print for EmptyStmt {
+    fn print(&self, source: &str, out: &mut String) {
+        token(source, &self.semi_whitespace, ";", out);
+    }
+}
+
+impl Print for ExprStmt {
+    fn print(&self, source: &str, out: &mut String) {
+        self.expr.print(source, out);
+        self.semi.print(source, out);
+    }
+}
+
+impl Print for IfStmt {
+    fn print(&self, source: &str, out: &mut String) {
+        token(source, &self.if_whitespace, "if", out);
+        token(source, &self.open_paren_whitespace, "(", out);
+        self.condition.print(source, out);
+        token(source, &self.close_paren_whitespace, ")", out);
+        self.cons.print(source, out);
+        if let (Some(whitespace), Some(alt)) = (&self.else_whitespace, &self.alt) {
+            token(source, whitespace, "else", out);
+            alt.print(source, out);
+        }
+    }
+}
+
+impl Print for Case {
+    fn print(&self, source: &str, out: &mut String) {
+        token(source, &self.whitespace, if self.default { "default" } else { "case" }, out);
+        self.test.print(source, out);
+        token(source, &self.colon_whitespace, ":", out);
+        self.cons.print(source, out);
+    }
+}
+
+impl Print for SwitchStmt {
+    fn print(&self, source: &str, out: &mut String) {
+        token(source, &self.switch_whitespace, "switch", out);
+        token(source, &self.open_paren_whitespace, "(", out);
+        self.test.print(source, out);
+        token(source, &self.close_paren_whitespace, ")", out);
+        token(source, &self.open_brace_whitespace, "{", out);
+        self.cases.print(source, out);
+        token(source, &self.close_brace_whitespace, "}", out);
+    }
+}
+
+impl Print for ThrowStmt {
+    fn print(&self, source: &str, out: &mut String) {
+        token(source, &self.throw_whitespace, "throw", out);
+        self.arg.print(source, out);
+        self.semi.print(source, out);
+    }
+}
+
+impl Print for WhileStmt {
+    fn print(&self, source: &str, out: &mut String) {
+        token(source, &self.while_whitespace, "while", out);
+        token(source, &self.open_paren_whitespace, "(", out);
+        self.condition.print(source, out);
+        token(source, &self.close_paren_whitespace, ")", out);
+        self.cons.print(source, out);
+    }
+}
+
+impl Print for DoWhileStmt {
+    fn print(&self, source: &str, out: &mut String) {
+        token(source, &self.do_whitespace, "do", out);
+        self.cons.print(source, out);
+        token(source, &self.while_whitespace, "while", out);
+        token(source, &self.open_paren_whitespace, "(", out);
+        self.condition.print(source, out);
+        token(source, &self.close_paren_whitespace, ")", out);
+    }
+}
+
+impl Print for LabelledStmt {
+    fn print(&self, source: &str, out: &mut String) {
+        self.label.print(source, out);
+        token(source, &self.colon_whitespace, ":", out);
+        self.body.print(source, out);
+    }
+}
+
+impl Print for BreakStmt {
+    fn print(&self, source: &str, out: &mut String) {
+        token(source, &self.break_whitespace, "break", out);
+        self.label.print(source, out);
+        self.semi.print(source, out);
+    }
+}
+
+impl Print for ContinueStmt {
+    fn print(&self, source: &str, out: &mut String) {
+        token(source, &self.continue_whitespace, "continue", out);
+        self.label.print(source, out);
+        self.semi.print(source, out);
+    }
+}
+
+impl Print for ReturnStmt {
+    fn print(&self, source: &str, out: &mut String) {
+        token(source, &self.return_whitespace, "return", out);
+        self.value.print(source, out);
+        self.semi.print(source, out);
+    }
+}
+
+impl Print for CatchClause {
+    fn print(&self, source: &str, out: &mut String) {
+        token(source, &self.catch_whitespace, "catch", out);
+        token(source, &self.open_paren_whitespace, "(", out);
+        self.param.print(source, out);
+        token(source, &self.close_paren_whitespace, ")", out);
+        self.body.print(source, out);
+    }
+}
+
+impl Print for TryStmt {
+    fn print(&self, source: &str, out: &mut String) {
+        token(source, &self.try_whitespace, "try", out);
+        self.test.print(source, out);
+        self.handler.print(source, out);
+        if let (Some(whitespace), Some(finalizer)) = (&self.final_whitespace, &self.finalizer) {
+            token(source, whitespace, "finally", out);
+            finalizer.print(source, out);
+        }
+    }
+}
+
+impl Print for ForStmtInit {
+    fn print(&self, source: &str, out: &mut String) {
+        match self {
+            // A `var` inside a `for (...)` head never owns its own
+            // semicolon - in a plain `ForStmt` the surrounding node prints
+            // its own `init_semicolon_whitespace` instead, and `ForInStmt`/
+            // `ForOfStmt` heads have no semicolon at all - so `var_stmt.semi`
+            // is always `Implicit` here.
+            ForStmtInit::Expr(expr) => expr.print(source, out),
+            ForStmtInit::Var(var_stmt) => var_stmt.print(source, out),
+        }
+    }
+}
+
+impl Print for ForStmt {
+    fn print(&self, source: &str, out: &mut String) {
+        token(source, &self.for_whitespace, "for", out);
+        token(source, &self.open_paren_whitespace, "(", out);
+        self.init.print(source, out);
+        token(source, &self.init_semicolon_whitespace, ";", out);
+        self.test.print(source, out);
+        token(source, &self.test_semicolon_whitespace, ";", out);
+        self.update.print(source, out);
+        token(source, &self.close_paren_whitespace, ")", out);
+        self.body.print(source, out);
+    }
+}
+
+impl Print for ForInStmt {
+    fn print(&self, source: &str, out: &mut String) {
+        token(source, &self.for_whitespace, "for", out);
+        token(source, &self.open_paren_whitespace, "(", out);
+        self.left.print(source, out);
+        token(source, &self.in_whitespace, "in", out);
+        self.right.print(source, out);
+        token(source, &self.close_paren_whitespace, ")", out);
+        self.body.print(source, out);
+    }
+}
+
+impl Print for ForOfStmt {
+    fn print(&self, source: &str, out: &mut String) {
+        token(source, &self.for_whitespace, "for", out);
+        token(source, &self.open_paren_whitespace, "(", out);
+        self.left.print(source, out);
+        token(source, &self.of_whitespace, "of", out);
+        self.right.print(source, out);
+        token(source, &self.close_paren_whitespace, ")", out);
+        self.body.print(source, out);
+    }
+}
+
+impl Print for WithStmt {
+    fn print(&self, source: &str, out: &mut String) {
+        token(source, &self.with_whitespace, "with", out);
+        token(source, &self.open_paren_whitespace, "(", out);
+        self.object.print(source, out);
+        token(source, &self.close_paren_whitespace, ")", out);
+        self.body.print(source, out);
+    }
+}
+
+impl Print for ErrorStmt {
+    // There's no structured content to walk here - the recovered range was
+    // never parsed into fields - so the only lossless option is to copy
+    // the bytes it spans straight out of the source.
+    fn print(&self, source: &str, out: &mut String) {
+        out.push_str(&source[self.span.start..self.span.end]);
+    }
+}
+
+impl Print for Stmt {
+    fn print(&self, source: &str, out: &mut String) {
+        use Stmt::*;
+        match self {
+            Variable(n) => n.print(source, out),
+            Empty(n) => n.print(source, out),
+            Block(n) => n.print(source, out),
+            Expr(n) => n.print(source, out),
+            If(n) => n.print(source, out),
+            Switch(n) => n.print(source, out),
+            Throw(n) => n.print(source, out),
+            While(n) => n.print(source, out),
+            DoWhile(n) => n.print(source, out),
+            Labelled(n) => n.print(source, out),
+            Break(n) => n.print(source, out),
+            Continue(n) => n.print(source, out),
+            Return(n) => n.print(source, out),
+            Try(n) => n.print(source, out),
+            For(n) => n.print(source, out),
+            ForIn(n) => n.print(source, out),
+            ForOf(n) => n.print(source, out),
+            With(n) => n.print(source, out),
+            Error(n) => n.print(source, out),
+        }
+    }
+}
+
+impl Print for StmtListItem {
+    fn print(&self, source: &str, out: &mut String) {
+        match self {
+            StmtListItem::Declaration(n) => n.print(source, out),
+            StmtListItem::Stmt(n) => n.print(source, out),
+        }
+    }
+}
+
+impl Print for ImportSpecifier {
+    fn print(&self, source: &str, out: &mut String) {
+        use ImportSpecifier::*;
+        match self {
+            Default(local) => local.print(source, out),
+            Namespace { star_whitespace, as_whitespace, local } => {
+                token(source, star_whitespace, "*", out);
+                token(source, as_whitespace, "as", out);
+                local.print(source, out);
+            }
+            Named { imported, as_whitespace, local, .. } => {
+                imported.print(source, out);
+                if let (Some(as_whitespace), Some(local)) = (as_whitespace, local) {
+                    token(source, as_whitespace, "as", out);
+                    local.print(source, out);
+                }
+            }
+        }
+    }
+}
+
+impl Print for ImportDecl {
+    fn print(&self, source: &str, out: &mut String) {
+        token(source, &self.import_whitespace, "import", out);
+        if let Some(open_brace_whitespace) = &self.open_brace_whitespace {
+            token(source, open_brace_whitespace, "{", out);
+        }
+        for (i, specifier) in self.specifiers.iter().enumerate() {
+            if i > 0 {
+                token(source, &self.comma_whitespaces[i - 1], ",", out);
+            }
+            specifier.print(source, out);
+        }
+        if let Some(close_brace_whitespace) = &self.close_brace_whitespace {
+            token(source, close_brace_whitespace, "}", out);
+        }
+        token(source, &self.from_whitespace, "from", out);
+        self.source.print(source, out);
+        self.semi.print(source, out);
+    }
+}
+
+impl Print for ExportSpecifier {
+    fn print(&self, source: &str, out: &mut String) {
+        self.local.print(source, out);
+        if let (Some(as_whitespace), Some(exported)) = (&self.as_whitespace, &self.exported) {
+            token(source, as_whitespace, "as", out);
+            exported.print(source, out);
+        }
+    }
+}
+
+impl Print for ExportDecl {
+    fn print(&self, source: &str, out: &mut String) {
+        use ExportDecl::*;
+        match self {
+            Named {
+                export_whitespace,
+                open_brace_whitespace,
+                specifiers,
+                comma_whitespaces,
+                close_brace_whitespace,
+                from_whitespace,
+                source: decl_source,
+                semi,
+                ..
+            } => {
+                token(source, export_whitespace, "export", out);
+                token(source, open_brace_whitespace, "{", out);
+                for (i, specifier) in specifiers.iter().enumerate() {
+                    if i > 0 {
+                        token(source, &comma_whitespaces[i - 1], ",", out);
+                    }
+                    specifier.print(source, out);
+                }
+                token(source, close_brace_whitespace, "}", out);
+                if let (Some(from_whitespace), Some(decl_source)) = (from_whitespace, decl_source) {
+                    token(source, from_whitespace, "from", out);
+                    decl_source.print(source, out);
+                }
+                semi.print(source, out);
+            }
+            Default { export_whitespace, default_whitespace, value, semi, .. } => {
+                token(source, export_whitespace, "export", out);
+                token(source, default_whitespace, "default", out);
+                value.print(source, out);
+                semi.print(source, out);
+            }
+            Declaration { export_whitespace, declaration, .. } => {
+                token(source, export_whitespace, "export", out);
+                declaration.print(source, out);
+            }
+            All { export_whitespace, star_whitespace, from_whitespace, source: decl_source, semi, .. } => {
+                token(source, export_whitespace, "export", out);
+                token(source, star_whitespace, "*", out);
+                token(source, from_whitespace, "from", out);
+                decl_source.print(source, out);
+                semi.print(source, out);
+            }
+        }
+    }
+}
+
+impl Print for ModuleItem {
+    fn print(&self, source: &str, out: &mut String) {
+        match self {
+            ModuleItem::StmtListItem(n) => n.print(source, out),
+            ModuleItem::Import(n) => n.print(source, out),
+            ModuleItem::Export(n) => n.print(source, out),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostic::Diagnostic;
+    use crate::span::Span;
+
+    // There's no `crate::parser::parse_script` (or any other source ->
+    // CST entry point) in this snapshot, so these tests build CST nodes
+    // by hand instead of parsing real source and print them back out,
+    // asserting byte-for-byte fidelity against the source they were
+    // built to represent - the same reprint-fidelity guarantee a
+    // test262-parser-tests-style corpus would exercise once a real
+    // parser exists to drive it end-to-end.
+    //
+    // `ForOfStmt` doesn't get one of these, and it's not the same gap as
+    // the others: every fixture above dodges `Expr`/`LiteralExpr` by
+    // picking a variant or state where those fields are optional and set
+    // to `None`/empty (`ExportDecl::Named`'s `source: None`, `specifiers:
+    // Vec::new()`; `ForStmtInit::Var` with `declared: Vec::new()` would
+    // work the same way for a `for`-head). `ForOfStmt::right` has no such
+    // escape - it's a bare `Expr`, not `Option<Expr>`, so printing or
+    // comparing any `ForOfStmt` at all means having one real `Expr` value
+    // in hand. `expr.rs` isn't part of this snapshot, and nothing in this
+    // crate - not one test, not one fixture - has ever constructed a
+    // concrete `Expr` or `LiteralExpr` value; they're only ever threaded
+    // through as opaque `None`s or empty collections. Guessing at `Expr`'s
+    // variants to build one here would be the first fabrication of a type
+    // this crate doesn't define, so `ForOfStmt` stays without a fixture
+    // until `expr.rs` exists to build one against.
+
+    fn ws(before: (usize, usize), after: (usize, usize)) -> LiteralWhitespace {
+        LiteralWhitespace {
+            before: Span::new(before.0, before.1),
+            after: Span::new(after.0, after.1),
+        }
+    }
+
+    #[test]
+    fn round_trips_empty_stmt() {
+        let source = ";";
+        let stmt = Stmt::Empty(EmptyStmt {
+            span: Span::new(0, 1),
+            semi_whitespace: ws((0, 0), (1, 1)),
+        });
+        let mut printed = String::new();
+        stmt.print(source, &mut printed);
+        assert_eq!(printed, source);
+    }
+
+    #[test]
+    fn round_trips_empty_block_stmt() {
+        let source = "{ ; }";
+        let stmt = Stmt::Block(BlockStmt {
+            span: Span::new(0, 5),
+            open_brace_whitespace: ws((0, 0), (1, 2)),
+            stmts: vec![Stmt::Empty(EmptyStmt {
+                span: Span::new(2, 3),
+                semi_whitespace: ws((2, 2), (3, 4)),
+            })],
+            close_brace_whitespace: ws((4, 4), (5, 5)),
+        });
+        let mut printed = String::new();
+        stmt.print(source, &mut printed);
+        assert_eq!(printed, source);
+    }
+
+    #[test]
+    fn round_trips_error_stmt() {
+        let source = "@@@";
+        let stmt = Stmt::Error(ErrorStmt {
+            span: Span::new(0, 3),
+            diagnostics: vec![Diagnostic::new(Span::new(0, 3), "unexpected token")],
+        });
+        let mut printed = String::new();
+        stmt.print(source, &mut printed);
+        assert_eq!(printed, source);
+    }
+
+    #[test]
+    fn round_trips_bare_named_export() {
+        let source = "export{};";
+        let item = ModuleItem::Export(ExportDecl::Named {
+            span: Span::new(0, 9),
+            export_whitespace: ws((0, 0), (6, 6)),
+            open_brace_whitespace: ws((6, 6), (7, 7)),
+            specifiers: Vec::new(),
+            comma_whitespaces: Vec::new(),
+            close_brace_whitespace: ws((7, 7), (8, 8)),
+            from_whitespace: None,
+            source: None,
+            semi: Semicolon::Explicit(ws((8, 8), (9, 9))),
+        });
+        let mut printed = String::new();
+        item.print(source, &mut printed);
+        assert_eq!(printed, source);
+    }
+}
@@ -0,0 +1,596 @@
+//! Hand-written traversal over the statement and module-item CST.
+//!
+//! [`Visit`] (shared refs) and [`VisitMut`] (mutable refs) give every node a
+//! `visit_*`/`visit_mut_*` method whose default body just walks into the
+//! node's children via the matching free `walk_*`/`walk_mut_*` function.
+//! Override only the methods you care about; everything else keeps
+//! recursing for you, so writing a lint rule or a transform no longer means
+//! hand-rolling a recursive match over every `Stmt` variant.
+//!
+//! This is *not* a derive: [`crate::define_stmt_visit`] only generates the
+//! `Stmt`-variant trait methods and the `walk_stmt`/`walk_mut_stmt` dispatch
+//! match from the table below, so adding a variant means one new line there
+//! instead of hand-editing the trait and both dispatchers by hand. The
+//! `walk_*`/`walk_mut_*` function each table row points at - the code that
+//! actually knows which fields of e.g. `IfStmt` to recurse into - is still
+//! hand-written per node either way; `macro_rules!` has no way to read a
+//! struct's fields for us.
+//!
+//! `Expr`/`LiteralExpr`/`Declaration` are treated as leaves here since this
+//! crate's expression/declaration traversal isn't wired up yet; `visit_expr`
+//! and friends are the hooks to override, they just don't recurse further
+//! on their own.
+//!
+//! [`ModuleItem`]/[`ImportDecl`]/[`ExportDecl`] aren't `Stmt` variants, so
+//! they're outside `define_stmt_visit!`'s table; their `visit_*`/`walk_*`
+//! pairs are hand-written below instead, the same way `Stmt`'s would be
+//! without the macro.
+
+use crate::define_stmt_visit;
+use crate::parser::cst::module::*;
+use crate::parser::cst::stmt::*;
+
+define_stmt_visit! {
+    extra {
+        fn visit_stmt(&mut self, node: &Stmt) {
+            walk_stmt(self, node);
+        }
+        fn visit_stmt_list_item(&mut self, node: &StmtListItem) {
+            walk_stmt_list_item(self, node);
+        }
+        fn visit_for_stmt_init(&mut self, node: &ForStmtInit) {
+            walk_for_stmt_init(self, node);
+        }
+        fn visit_expr(&mut self, _node: &Expr) {}
+        fn visit_literal_expr(&mut self, _node: &LiteralExpr) {}
+        fn visit_declaration(&mut self, _node: &Declaration) {}
+        fn visit_module_item(&mut self, node: &ModuleItem) {
+            walk_module_item(self, node);
+        }
+        fn visit_import_decl(&mut self, node: &ImportDecl) {
+            walk_import_decl(self, node);
+        }
+        fn visit_import_specifier(&mut self, node: &ImportSpecifier) {
+            walk_import_specifier(self, node);
+        }
+        fn visit_export_decl(&mut self, node: &ExportDecl) {
+            walk_export_decl(self, node);
+        }
+        fn visit_export_specifier(&mut self, node: &ExportSpecifier) {
+            walk_export_specifier(self, node);
+        }
+    }
+    extra_mut {
+        fn visit_mut_stmt(&mut self, node: &mut Stmt) {
+            walk_mut_stmt(self, node);
+        }
+        fn visit_mut_stmt_list_item(&mut self, node: &mut StmtListItem) {
+            walk_mut_stmt_list_item(self, node);
+        }
+        fn visit_mut_for_stmt_init(&mut self, node: &mut ForStmtInit) {
+            walk_mut_for_stmt_init(self, node);
+        }
+        fn visit_mut_expr(&mut self, _node: &mut Expr) {}
+        fn visit_mut_literal_expr(&mut self, _node: &mut LiteralExpr) {}
+        fn visit_mut_declaration(&mut self, _node: &mut Declaration) {}
+        fn visit_mut_module_item(&mut self, node: &mut ModuleItem) {
+            walk_mut_module_item(self, node);
+        }
+        fn visit_mut_import_decl(&mut self, node: &mut ImportDecl) {
+            walk_mut_import_decl(self, node);
+        }
+        fn visit_mut_import_specifier(&mut self, node: &mut ImportSpecifier) {
+            walk_mut_import_specifier(self, node);
+        }
+        fn visit_mut_export_decl(&mut self, node: &mut ExportDecl) {
+            walk_mut_export_decl(self, node);
+        }
+        fn visit_mut_export_specifier(&mut self, node: &mut ExportSpecifier) {
+            walk_mut_export_specifier(self, node);
+        }
+    }
+    variants {
+        Variable(VarStmt) => visit_var_stmt, walk_var_stmt, visit_mut_var_stmt, walk_mut_var_stmt;
+        Empty(EmptyStmt) => visit_empty_stmt, walk_empty_stmt, visit_mut_empty_stmt, walk_mut_empty_stmt;
+        Block(BlockStmt) => visit_block_stmt, walk_block_stmt, visit_mut_block_stmt, walk_mut_block_stmt;
+        Expr(ExprStmt) => visit_expr_stmt, walk_expr_stmt, visit_mut_expr_stmt, walk_mut_expr_stmt;
+        If(IfStmt) => visit_if_stmt, walk_if_stmt, visit_mut_if_stmt, walk_mut_if_stmt;
+        Switch(SwitchStmt) => visit_switch_stmt, walk_switch_stmt, visit_mut_switch_stmt, walk_mut_switch_stmt;
+        Throw(ThrowStmt) => visit_throw_stmt, walk_throw_stmt, visit_mut_throw_stmt, walk_mut_throw_stmt;
+        While(WhileStmt) => visit_while_stmt, walk_while_stmt, visit_mut_while_stmt, walk_mut_while_stmt;
+        DoWhile(DoWhileStmt) => visit_do_while_stmt, walk_do_while_stmt, visit_mut_do_while_stmt, walk_mut_do_while_stmt;
+        Labelled(LabelledStmt) => visit_labelled_stmt, walk_labelled_stmt, visit_mut_labelled_stmt, walk_mut_labelled_stmt;
+        Break(BreakStmt) => visit_break_stmt, walk_break_stmt, visit_mut_break_stmt, walk_mut_break_stmt;
+        Continue(ContinueStmt) => visit_continue_stmt, walk_continue_stmt, visit_mut_continue_stmt, walk_mut_continue_stmt;
+        Return(ReturnStmt) => visit_return_stmt, walk_return_stmt, visit_mut_return_stmt, walk_mut_return_stmt;
+        Try(TryStmt) => visit_try_stmt, walk_try_stmt, visit_mut_try_stmt, walk_mut_try_stmt;
+        For(ForStmt) => visit_for_stmt, walk_for_stmt, visit_mut_for_stmt, walk_mut_for_stmt;
+        ForIn(ForInStmt) => visit_for_in_stmt, walk_for_in_stmt, visit_mut_for_in_stmt, walk_mut_for_in_stmt;
+        ForOf(ForOfStmt) => visit_for_of_stmt, walk_for_of_stmt, visit_mut_for_of_stmt, walk_mut_for_of_stmt;
+        With(WithStmt) => visit_with_stmt, walk_with_stmt, visit_mut_with_stmt, walk_mut_with_stmt;
+        Error(ErrorStmt) => visit_error_stmt, walk_error_stmt, visit_mut_error_stmt, walk_mut_error_stmt;
+    }
+}
+
+pub fn walk_stmt_list_item<V: Visit + ?Sized>(v: &mut V, node: &StmtListItem) {
+    match node {
+        StmtListItem::Declaration(n) => v.visit_declaration(n),
+        StmtListItem::Stmt(n) => v.visit_stmt(n),
+    }
+}
+
+pub fn walk_var_stmt<V: Visit + ?Sized>(v: &mut V, node: &VarStmt) {
+    for declarator in &node.declared {
+        v.visit_literal_expr(&declarator.name);
+        if let Some(value) = &declarator.value {
+            v.visit_expr(value);
+        }
+    }
+}
+
+pub fn walk_empty_stmt<V: Visit + ?Sized>(_v: &mut V, _node: &EmptyStmt) {}
+
+pub fn walk_block_stmt<V: Visit + ?Sized>(v: &mut V, node: &BlockStmt) {
+    for stmt in &node.stmts {
+        v.visit_stmt(stmt);
+    }
+}
+
+pub fn walk_expr_stmt<V: Visit + ?Sized>(v: &mut V, node: &ExprStmt) {
+    v.visit_expr(&node.expr);
+}
+
+pub fn walk_if_stmt<V: Visit + ?Sized>(v: &mut V, node: &IfStmt) {
+    v.visit_expr(&node.condition);
+    v.visit_stmt(&node.cons);
+    if let Some(alt) = &node.alt {
+        v.visit_stmt(alt);
+    }
+}
+
+pub fn walk_switch_stmt<V: Visit + ?Sized>(v: &mut V, node: &SwitchStmt) {
+    v.visit_expr(&node.test);
+    for case in &node.cases {
+        if let Some(test) = &case.test {
+            v.visit_expr(test);
+        }
+        for stmt in &case.cons {
+            v.visit_stmt(stmt);
+        }
+    }
+}
+
+pub fn walk_throw_stmt<V: Visit + ?Sized>(v: &mut V, node: &ThrowStmt) {
+    v.visit_expr(&node.arg);
+}
+
+pub fn walk_while_stmt<V: Visit + ?Sized>(v: &mut V, node: &WhileStmt) {
+    v.visit_expr(&node.condition);
+    v.visit_stmt(&node.cons);
+}
+
+pub fn walk_do_while_stmt<V: Visit + ?Sized>(v: &mut V, node: &DoWhileStmt) {
+    v.visit_stmt(&node.cons);
+    v.visit_expr(&node.condition);
+}
+
+pub fn walk_labelled_stmt<V: Visit + ?Sized>(v: &mut V, node: &LabelledStmt) {
+    v.visit_literal_expr(&node.label);
+    v.visit_stmt(&node.body);
+}
+
+pub fn walk_break_stmt<V: Visit + ?Sized>(v: &mut V, node: &BreakStmt) {
+    if let Some(label) = &node.label {
+        v.visit_literal_expr(label);
+    }
+}
+
+pub fn walk_continue_stmt<V: Visit + ?Sized>(v: &mut V, node: &ContinueStmt) {
+    if let Some(label) = &node.label {
+        v.visit_literal_expr(label);
+    }
+}
+
+pub fn walk_return_stmt<V: Visit + ?Sized>(v: &mut V, node: &ReturnStmt) {
+    if let Some(value) = &node.value {
+        v.visit_expr(value);
+    }
+}
+
+pub fn walk_try_stmt<V: Visit + ?Sized>(v: &mut V, node: &TryStmt) {
+    v.visit_block_stmt(&node.test);
+    if let Some(handler) = &node.handler {
+        v.visit_literal_expr(&handler.param);
+        v.visit_block_stmt(&handler.body);
+    }
+    if let Some(finalizer) = &node.finalizer {
+        v.visit_block_stmt(finalizer);
+    }
+}
+
+pub fn walk_for_stmt<V: Visit + ?Sized>(v: &mut V, node: &ForStmt) {
+    if let Some(init) = &node.init {
+        v.visit_for_stmt_init(init);
+    }
+    if let Some(test) = &node.test {
+        v.visit_expr(test);
+    }
+    if let Some(update) = &node.update {
+        v.visit_expr(update);
+    }
+    v.visit_stmt(&node.body);
+}
+
+pub fn walk_for_in_stmt<V: Visit + ?Sized>(v: &mut V, node: &ForInStmt) {
+    v.visit_for_stmt_init(&node.left);
+    v.visit_expr(&node.right);
+    v.visit_stmt(&node.body);
+}
+
+pub fn walk_for_of_stmt<V: Visit + ?Sized>(v: &mut V, node: &ForOfStmt) {
+    v.visit_for_stmt_init(&node.left);
+    v.visit_expr(&node.right);
+    v.visit_stmt(&node.body);
+}
+
+pub fn walk_with_stmt<V: Visit + ?Sized>(v: &mut V, node: &WithStmt) {
+    v.visit_expr(&node.object);
+    v.visit_stmt(&node.body);
+}
+
+pub fn walk_error_stmt<V: Visit + ?Sized>(_v: &mut V, _node: &ErrorStmt) {}
+
+pub fn walk_for_stmt_init<V: Visit + ?Sized>(v: &mut V, node: &ForStmtInit) {
+    match node {
+        ForStmtInit::Expr(expr) => v.visit_expr(expr),
+        ForStmtInit::Var(var_stmt) => v.visit_var_stmt(var_stmt),
+    }
+}
+
+pub fn walk_module_item<V: Visit + ?Sized>(v: &mut V, node: &ModuleItem) {
+    match node {
+        ModuleItem::StmtListItem(n) => v.visit_stmt_list_item(n),
+        ModuleItem::Import(n) => v.visit_import_decl(n),
+        ModuleItem::Export(n) => v.visit_export_decl(n),
+    }
+}
+
+pub fn walk_import_decl<V: Visit + ?Sized>(v: &mut V, node: &ImportDecl) {
+    for specifier in &node.specifiers {
+        v.visit_import_specifier(specifier);
+    }
+    v.visit_literal_expr(&node.source);
+}
+
+pub fn walk_import_specifier<V: Visit + ?Sized>(v: &mut V, node: &ImportSpecifier) {
+    match node {
+        ImportSpecifier::Default(name) => v.visit_literal_expr(name),
+        ImportSpecifier::Namespace { local, .. } => v.visit_literal_expr(local),
+        ImportSpecifier::Named { imported, local, .. } => {
+            v.visit_literal_expr(imported);
+            if let Some(local) = local {
+                v.visit_literal_expr(local);
+            }
+        }
+    }
+}
+
+pub fn walk_export_decl<V: Visit + ?Sized>(v: &mut V, node: &ExportDecl) {
+    match node {
+        ExportDecl::Named { specifiers, source, .. } => {
+            for specifier in specifiers {
+                v.visit_export_specifier(specifier);
+            }
+            if let Some(source) = source {
+                v.visit_literal_expr(source);
+            }
+        }
+        ExportDecl::Default { value, .. } => v.visit_expr(value),
+        ExportDecl::Declaration { declaration, .. } => v.visit_declaration(declaration),
+        ExportDecl::All { source, .. } => v.visit_literal_expr(source),
+    }
+}
+
+pub fn walk_export_specifier<V: Visit + ?Sized>(v: &mut V, node: &ExportSpecifier) {
+    v.visit_literal_expr(&node.local);
+    if let Some(exported) = &node.exported {
+        v.visit_literal_expr(exported);
+    }
+}
+
+pub fn walk_mut_stmt_list_item<V: VisitMut + ?Sized>(v: &mut V, node: &mut StmtListItem) {
+    match node {
+        StmtListItem::Declaration(n) => v.visit_mut_declaration(n),
+        StmtListItem::Stmt(n) => v.visit_mut_stmt(n),
+    }
+}
+
+pub fn walk_mut_var_stmt<V: VisitMut + ?Sized>(v: &mut V, node: &mut VarStmt) {
+    for declarator in &mut node.declared {
+        v.visit_mut_literal_expr(&mut declarator.name);
+        if let Some(value) = &mut declarator.value {
+            v.visit_mut_expr(value);
+        }
+    }
+}
+
+pub fn walk_mut_empty_stmt<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut EmptyStmt) {}
+
+pub fn walk_mut_block_stmt<V: VisitMut + ?Sized>(v: &mut V, node: &mut BlockStmt) {
+    for stmt in &mut node.stmts {
+        v.visit_mut_stmt(stmt);
+    }
+}
+
+pub fn walk_mut_expr_stmt<V: VisitMut + ?Sized>(v: &mut V, node: &mut ExprStmt) {
+    v.visit_mut_expr(&mut node.expr);
+}
+
+pub fn walk_mut_if_stmt<V: VisitMut + ?Sized>(v: &mut V, node: &mut IfStmt) {
+    v.visit_mut_expr(&mut node.condition);
+    v.visit_mut_stmt(&mut node.cons);
+    if let Some(alt) = &mut node.alt {
+        v.visit_mut_stmt(alt);
+    }
+}
+
+pub fn walk_mut_switch_stmt<V: VisitMut + ?Sized>(v: &mut V, node: &mut SwitchStmt) {
+    v.visit_mut_expr(&mut node.test);
+    for case in &mut node.cases {
+        if let Some(test) = &mut case.test {
+            v.visit_mut_expr(test);
+        }
+        for stmt in &mut case.cons {
+            v.visit_mut_stmt(stmt);
+        }
+    }
+}
+
+pub fn walk_mut_throw_stmt<V: VisitMut + ?Sized>(v: &mut V, node: &mut ThrowStmt) {
+    v.visit_mut_expr(&mut node.arg);
+}
+
+pub fn walk_mut_while_stmt<V: VisitMut + ?Sized>(v: &mut V, node: &mut WhileStmt) {
+    v.visit_mut_expr(&mut node.condition);
+    v.visit_mut_stmt(&mut node.cons);
+}
+
+pub fn walk_mut_do_while_stmt<V: VisitMut + ?Sized>(v: &mut V, node: &mut DoWhileStmt) {
+    v.visit_mut_stmt(&mut node.cons);
+    v.visit_mut_expr(&mut node.condition);
+}
+
+pub fn walk_mut_labelled_stmt<V: VisitMut + ?Sized>(v: &mut V, node: &mut LabelledStmt) {
+    v.visit_mut_literal_expr(&mut node.label);
+    v.visit_mut_stmt(&mut node.body);
+}
+
+pub fn walk_mut_break_stmt<V: VisitMut + ?Sized>(v: &mut V, node: &mut BreakStmt) {
+    if let Some(label) = &mut node.label {
+        v.visit_mut_literal_expr(label);
+    }
+}
+
+pub fn walk_mut_continue_stmt<V: VisitMut + ?Sized>(v: &mut V, node: &mut ContinueStmt) {
+    if let Some(label) = &mut node.label {
+        v.visit_mut_literal_expr(label);
+    }
+}
+
+pub fn walk_mut_return_stmt<V: VisitMut + ?Sized>(v: &mut V, node: &mut ReturnStmt) {
+    if let Some(value) = &mut node.value {
+        v.visit_mut_expr(value);
+    }
+}
+
+pub fn walk_mut_try_stmt<V: VisitMut + ?Sized>(v: &mut V, node: &mut TryStmt) {
+    v.visit_mut_block_stmt(&mut node.test);
+    if let Some(handler) = &mut node.handler {
+        v.visit_mut_literal_expr(&mut handler.param);
+        v.visit_mut_block_stmt(&mut handler.body);
+    }
+    if let Some(finalizer) = &mut node.finalizer {
+        v.visit_mut_block_stmt(finalizer);
+    }
+}
+
+pub fn walk_mut_for_stmt<V: VisitMut + ?Sized>(v: &mut V, node: &mut ForStmt) {
+    if let Some(init) = &mut node.init {
+        v.visit_mut_for_stmt_init(init);
+    }
+    if let Some(test) = &mut node.test {
+        v.visit_mut_expr(test);
+    }
+    if let Some(update) = &mut node.update {
+        v.visit_mut_expr(update);
+    }
+    v.visit_mut_stmt(&mut node.body);
+}
+
+pub fn walk_mut_for_in_stmt<V: VisitMut + ?Sized>(v: &mut V, node: &mut ForInStmt) {
+    v.visit_mut_for_stmt_init(&mut node.left);
+    v.visit_mut_expr(&mut node.right);
+    v.visit_mut_stmt(&mut node.body);
+}
+
+pub fn walk_mut_for_of_stmt<V: VisitMut + ?Sized>(v: &mut V, node: &mut ForOfStmt) {
+    v.visit_mut_for_stmt_init(&mut node.left);
+    v.visit_mut_expr(&mut node.right);
+    v.visit_mut_stmt(&mut node.body);
+}
+
+pub fn walk_mut_with_stmt<V: VisitMut + ?Sized>(v: &mut V, node: &mut WithStmt) {
+    v.visit_mut_expr(&mut node.object);
+    v.visit_mut_stmt(&mut node.body);
+}
+
+pub fn walk_mut_error_stmt<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut ErrorStmt) {}
+
+pub fn walk_mut_for_stmt_init<V: VisitMut + ?Sized>(v: &mut V, node: &mut ForStmtInit) {
+    match node {
+        ForStmtInit::Expr(expr) => v.visit_mut_expr(expr),
+        ForStmtInit::Var(var_stmt) => v.visit_mut_var_stmt(var_stmt),
+    }
+}
+
+pub fn walk_mut_module_item<V: VisitMut + ?Sized>(v: &mut V, node: &mut ModuleItem) {
+    match node {
+        ModuleItem::StmtListItem(n) => v.visit_mut_stmt_list_item(n),
+        ModuleItem::Import(n) => v.visit_mut_import_decl(n),
+        ModuleItem::Export(n) => v.visit_mut_export_decl(n),
+    }
+}
+
+pub fn walk_mut_import_decl<V: VisitMut + ?Sized>(v: &mut V, node: &mut ImportDecl) {
+    for specifier in &mut node.specifiers {
+        v.visit_mut_import_specifier(specifier);
+    }
+    v.visit_mut_literal_expr(&mut node.source);
+}
+
+pub fn walk_mut_import_specifier<V: VisitMut + ?Sized>(v: &mut V, node: &mut ImportSpecifier) {
+    match node {
+        ImportSpecifier::Default(name) => v.visit_mut_literal_expr(name),
+        ImportSpecifier::Namespace { local, .. } => v.visit_mut_literal_expr(local),
+        ImportSpecifier::Named { imported, local, .. } => {
+            v.visit_mut_literal_expr(imported);
+            if let Some(local) = local {
+                v.visit_mut_literal_expr(local);
+            }
+        }
+    }
+}
+
+pub fn walk_mut_export_decl<V: VisitMut + ?Sized>(v: &mut V, node: &mut ExportDecl) {
+    match node {
+        ExportDecl::Named { specifiers, source, .. } => {
+            for specifier in specifiers {
+                v.visit_mut_export_specifier(specifier);
+            }
+            if let Some(source) = source {
+                v.visit_mut_literal_expr(source);
+            }
+        }
+        ExportDecl::Default { value, .. } => v.visit_mut_expr(value),
+        ExportDecl::Declaration { declaration, .. } => v.visit_mut_declaration(declaration),
+        ExportDecl::All { source, .. } => v.visit_mut_literal_expr(source),
+    }
+}
+
+pub fn walk_mut_export_specifier<V: VisitMut + ?Sized>(v: &mut V, node: &mut ExportSpecifier) {
+    v.visit_mut_literal_expr(&mut node.local);
+    if let Some(exported) = &mut node.exported {
+        v.visit_mut_literal_expr(exported);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_eq_ignore_span;
+    use crate::macros::EqIgnoreSpan;
+    use crate::parser::cst::expr::LiteralWhitespace;
+    use crate::span::Span;
+
+    // All of these fixtures stick to variants whose fields are `Span`,
+    // `LiteralWhitespace`, or `Option`/`Vec` of those - no `Expr`/
+    // `LiteralExpr`/`Declaration`, which aren't part of this snapshot.
+    fn ws(span: Span) -> LiteralWhitespace {
+        LiteralWhitespace { before: span, after: span }
+    }
+
+    fn empty_block(span: Span) -> BlockStmt {
+        BlockStmt {
+            span,
+            stmts: Vec::new(),
+            open_brace_whitespace: ws(span),
+            close_brace_whitespace: ws(span),
+        }
+    }
+
+    fn sample_tree(span: Span) -> Stmt {
+        Stmt::Block(BlockStmt {
+            stmts: vec![
+                Stmt::Empty(EmptyStmt { span, semi_whitespace: ws(span) }),
+                Stmt::Return(ReturnStmt { span, return_whitespace: ws(span), value: None, semi: Semicolon::Implicit }),
+                Stmt::Break(BreakStmt { span, break_whitespace: ws(span), label: None, semi: Semicolon::Implicit }),
+                Stmt::Try(TryStmt {
+                    span,
+                    try_whitespace: ws(span),
+                    test: empty_block(span),
+                    handler: None,
+                    finalizer: None,
+                    final_whitespace: None,
+                }),
+            ],
+            ..empty_block(span)
+        })
+    }
+
+    struct Collector {
+        order: Vec<&'static str>,
+    }
+
+    impl Visit for Collector {
+        fn visit_block_stmt(&mut self, node: &BlockStmt) {
+            self.order.push("block");
+            walk_block_stmt(self, node);
+        }
+        fn visit_empty_stmt(&mut self, node: &EmptyStmt) {
+            self.order.push("empty");
+            walk_empty_stmt(self, node);
+        }
+        fn visit_return_stmt(&mut self, node: &ReturnStmt) {
+            self.order.push("return");
+            walk_return_stmt(self, node);
+        }
+        fn visit_break_stmt(&mut self, node: &BreakStmt) {
+            self.order.push("break");
+            walk_break_stmt(self, node);
+        }
+        fn visit_try_stmt(&mut self, node: &TryStmt) {
+            self.order.push("try");
+            walk_try_stmt(self, node);
+        }
+    }
+
+    #[test]
+    fn visit_walks_every_node_in_source_order() {
+        let tree = sample_tree(Span::new(0, 0));
+        let mut collector = Collector { order: Vec::new() };
+        collector.visit_stmt(&tree);
+        assert_eq!(collector.order, ["block", "empty", "return", "break", "try", "block"]);
+    }
+
+    struct BlockPadder;
+
+    impl VisitMut for BlockPadder {
+        fn visit_mut_block_stmt(&mut self, node: &mut BlockStmt) {
+            walk_mut_block_stmt(self, node);
+            node.stmts.push(Stmt::Empty(EmptyStmt { span: node.span, semi_whitespace: ws(node.span) }));
+        }
+    }
+
+    #[test]
+    fn visit_mut_reaches_nested_blocks() {
+        let mut tree = sample_tree(Span::new(0, 0));
+        BlockPadder.visit_mut_stmt(&mut tree);
+
+        let Stmt::Block(outer) = &tree else { panic!("expected a block") };
+        assert_eq!(outer.stmts.len(), 5);
+
+        let Stmt::Try(try_stmt) = &outer.stmts[3] else { panic!("expected a try statement") };
+        assert_eq!(try_stmt.test.stmts.len(), 1);
+    }
+
+    #[test]
+    fn assert_eq_ignore_span_compares_shape_not_spans() {
+        let a = sample_tree(Span::new(0, 0));
+        let b = sample_tree(Span::new(100, 100));
+        assert_eq_ignore_span!(a, b);
+
+        let Stmt::Block(mut shifted) = b else { panic!("expected a block") };
+        shifted.stmts.push(Stmt::Empty(EmptyStmt { span: Span::new(100, 100), semi_whitespace: ws(Span::new(100, 100)) }));
+        assert!(!a.eq_ignore_span(&Stmt::Block(shifted)));
+    }
+}